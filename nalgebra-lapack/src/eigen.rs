@@ -311,6 +311,304 @@ where
 
         det
     }
+
+    /// Computes the eigenvalues and eigenvectors of the square matrix `m`, properly unpacking
+    /// complex conjugate pairs instead of discarding them.
+    ///
+    /// Unlike [`Eigen::new`], this never fails because of complex eigenvalues: `dgeev`/`sgeev`
+    /// store a complex conjugate pair `wr[j] ± i·wi[j]` as two consecutive real columns, with
+    /// `wr[j+1] == wr[j]` and `wi[j+1] == -wi[j]`. This reconstructs the true complex eigenvalues
+    /// and eigenvectors from that packed storage.
+    ///
+    /// If `eigenvectors` is `false` then, the eigenvectors are not computed explicitly.
+    pub fn new_complex(
+        mut m: OMatrix<T, D, D>,
+        left_eigenvectors: bool,
+        eigenvectors: bool,
+    ) -> Option<ComplexEigen<T, D>>
+    where
+        DefaultAllocator: Allocator<Complex<T>, D> + Allocator<Complex<T>, D, D>,
+    {
+        assert!(
+            m.is_square(),
+            "Unable to compute the eigenvalue decomposition of a non-square matrix."
+        );
+
+        let ljob = if left_eigenvectors { b'V' } else { b'T' };
+        let rjob = if eigenvectors { b'V' } else { b'T' };
+
+        let (nrows, ncols) = m.shape_generic();
+        let n = nrows.value();
+
+        let lda = n as i32;
+
+        // TODO: avoid the initialization?
+        let mut wr = Matrix::zeros_generic(nrows, Const::<1>);
+        // TODO: Tap into the workspace.
+        let mut wi = Matrix::zeros_generic(nrows, Const::<1>);
+
+        let mut info = 0;
+        let mut placeholder1 = [T::zero()];
+        let mut placeholder2 = [T::zero()];
+
+        let lwork = T::xgeev_work_size(
+            ljob,
+            rjob,
+            n as i32,
+            m.as_mut_slice(),
+            lda,
+            wr.as_mut_slice(),
+            wi.as_mut_slice(),
+            &mut placeholder1,
+            n as i32,
+            &mut placeholder2,
+            n as i32,
+            &mut info,
+        );
+
+        lapack_check!(info);
+
+        let mut work = vec![T::zero(); lwork as usize];
+
+        let mut vl = if left_eigenvectors {
+            Some(Matrix::zeros_generic(nrows, ncols))
+        } else {
+            None
+        };
+        let mut vr = if eigenvectors {
+            Some(Matrix::zeros_generic(nrows, ncols))
+        } else {
+            None
+        };
+
+        T::xgeev(
+            ljob,
+            rjob,
+            n as i32,
+            m.as_mut_slice(),
+            lda,
+            wr.as_mut_slice(),
+            wi.as_mut_slice(),
+            vl.as_mut().map_or(&mut placeholder1[..], |vl| vl.as_mut_slice()),
+            if left_eigenvectors { n as i32 } else { 1 },
+            vr.as_mut().map_or(&mut placeholder2[..], |vr| vr.as_mut_slice()),
+            if eigenvectors { n as i32 } else { 1 },
+            &mut work,
+            lwork,
+            &mut info,
+        );
+        lapack_check!(info);
+
+        let mut complex_eigenvalues = Matrix::zeros_generic(nrows, Const::<1>);
+        for i in 0..n {
+            complex_eigenvalues[i] = Complex::new(wr[i], wi[i]);
+        }
+
+        Some(ComplexEigen {
+            eigenvalues: complex_eigenvalues,
+            eigenvectors: vr.map(|vr| reconstruct_complex_eigenvectors(&wi, &vr)),
+            left_eigenvectors: vl.map(|vl| reconstruct_complex_eigenvectors(&wi, &vl)),
+        })
+    }
+
+    /// Computes the eigenvalues and eigenvectors of the square matrix `m`, together with
+    /// reciprocal condition number estimates, using LAPACK's expert driver `dgeevx`/`sgeevx`.
+    ///
+    /// Unlike [`Eigen::new`], which gives no indication of how trustworthy a near-defective
+    /// eigenvalue is, this exposes `rconde` (the reciprocal condition numbers of the
+    /// eigenvalues) and `rcondv` (of the eigenvectors), as well as the balancing/permutation
+    /// scale factors LAPACK applied before the computation.
+    ///
+    /// `dgeevx`/`sgeevx` require `JOBVL = JOBVR = 'V'` whenever `SENSE = 'B'`, so both the left
+    /// and right eigenvectors are always computed internally regardless of `left_eigenvectors`/
+    /// `eigenvectors`; those flags only control which ones are kept in the returned value.
+    ///
+    /// Like [`Eigen::new_complex`], this reconstructs complex eigenvalues/eigenvectors from
+    /// LAPACK's packed conjugate-pair storage instead of discarding the decomposition when the
+    /// matrix has complex eigenvalues.
+    pub fn new_with_conditioning(
+        mut m: OMatrix<T, D, D>,
+        left_eigenvectors: bool,
+        eigenvectors: bool,
+    ) -> Option<EigenConditioning<T, D>>
+    where
+        DefaultAllocator: Allocator<Complex<T>, D> + Allocator<Complex<T>, D, D>,
+    {
+        assert!(
+            m.is_square(),
+            "Unable to compute the eigenvalue decomposition of a non-square matrix."
+        );
+
+        let (nrows, ncols) = m.shape_generic();
+        let n = nrows.value();
+
+        let lda = n as i32;
+
+        // TODO: avoid the initializations?
+        let mut wr = Matrix::zeros_generic(nrows, Const::<1>);
+        let mut wi = Matrix::zeros_generic(nrows, Const::<1>);
+        let mut scale = Matrix::zeros_generic(nrows, Const::<1>);
+        let mut rconde = Matrix::zeros_generic(nrows, Const::<1>);
+        let mut rcondv = Matrix::zeros_generic(nrows, Const::<1>);
+        let mut vl = Matrix::zeros_generic(nrows, ncols);
+        let mut vr = Matrix::zeros_generic(nrows, ncols);
+
+        let mut info = 0;
+        let mut ilo = 0;
+        let mut ihi = 0;
+        let mut abnrm = T::zero();
+
+        let lwork = T::xgeevx_work_size(
+            b'B',
+            b'V',
+            b'V',
+            b'B',
+            n as i32,
+            m.as_mut_slice(),
+            lda,
+            wr.as_mut_slice(),
+            wi.as_mut_slice(),
+            vl.as_mut_slice(),
+            n as i32,
+            vr.as_mut_slice(),
+            n as i32,
+            &mut ilo,
+            &mut ihi,
+            scale.as_mut_slice(),
+            &mut abnrm,
+            rconde.as_mut_slice(),
+            rcondv.as_mut_slice(),
+            &mut info,
+        );
+
+        lapack_check!(info);
+
+        let mut work = vec![T::zero(); lwork as usize];
+        let mut iwork = vec![0; (2 * n).saturating_sub(2).max(1)];
+
+        T::xgeevx(
+            b'B',
+            b'V',
+            b'V',
+            b'B',
+            n as i32,
+            m.as_mut_slice(),
+            lda,
+            wr.as_mut_slice(),
+            wi.as_mut_slice(),
+            vl.as_mut_slice(),
+            n as i32,
+            vr.as_mut_slice(),
+            n as i32,
+            &mut ilo,
+            &mut ihi,
+            scale.as_mut_slice(),
+            &mut abnrm,
+            rconde.as_mut_slice(),
+            rcondv.as_mut_slice(),
+            &mut work,
+            lwork,
+            &mut iwork,
+            &mut info,
+        );
+        lapack_check!(info);
+
+        let mut complex_eigenvalues = Matrix::zeros_generic(nrows, Const::<1>);
+        for i in 0..n {
+            complex_eigenvalues[i] = Complex::new(wr[i], wi[i]);
+        }
+
+        Some(EigenConditioning {
+            eigenvalues: complex_eigenvalues,
+            eigenvectors: if eigenvectors {
+                Some(reconstruct_complex_eigenvectors(&wi, &vr))
+            } else {
+                None
+            },
+            left_eigenvectors: if left_eigenvectors {
+                Some(reconstruct_complex_eigenvectors(&wi, &vl))
+            } else {
+                None
+            },
+            rconde,
+            rcondv,
+            scale,
+        })
+    }
+}
+
+/// Eigendecomposition of a real square matrix with complex eigenvalues and eigenvectors,
+/// together with the reciprocal condition number estimates produced by LAPACK's expert driver
+/// `dgeevx`/`sgeevx`.
+#[derive(Clone, Debug)]
+pub struct EigenConditioning<T: Scalar, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<Complex<T>, D> + Allocator<Complex<T>, D, D>,
+{
+    /// The eigenvalues of the decomposed matrix.
+    pub eigenvalues: OVector<Complex<T>, D>,
+    /// The (right) eigenvectors of the decomposed matrix.
+    pub eigenvectors: Option<OMatrix<Complex<T>, D, D>>,
+    /// The left eigenvectors of the decomposed matrix.
+    pub left_eigenvectors: Option<OMatrix<Complex<T>, D, D>>,
+    /// The reciprocal condition numbers of the eigenvalues.
+    pub rconde: OVector<T, D>,
+    /// The reciprocal condition numbers of the eigenvectors.
+    pub rcondv: OVector<T, D>,
+    /// The balancing/permutation scale factors applied to the matrix before computation.
+    pub scale: OVector<T, D>,
+}
+
+/// Reconstructs a complex eigenvector matrix from LAPACK's packed real storage.
+///
+/// Column `j` of `v` holds the real part of eigenvector `j` whenever `wi[j]` is zero. Otherwise
+/// `wi[j] > 0` and columns `j`, `j + 1` hold the real and imaginary parts of a complex conjugate
+/// pair: eigenvector `j` is `v[:, j] + i·v[:, j + 1]` and eigenvector `j + 1` is its conjugate.
+fn reconstruct_complex_eigenvectors<T: RealField, D: Dim>(
+    wi: &OVector<T, D>,
+    v: &OMatrix<T, D, D>,
+) -> OMatrix<Complex<T>, D, D>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D> + Allocator<Complex<T>, D, D>,
+{
+    let (nrows, ncols) = v.shape_generic();
+    let n = nrows.value();
+    let mut result = Matrix::zeros_generic(nrows, ncols);
+
+    let mut j = 0;
+    while j < n {
+        if !wi[j].is_zero() {
+            for i in 0..n {
+                let re = v[(i, j)];
+                let im = v[(i, j + 1)];
+                result[(i, j)] = Complex::new(re, im);
+                result[(i, j + 1)] = Complex::new(re, -im);
+            }
+            j += 2;
+        } else {
+            for i in 0..n {
+                result[(i, j)] = Complex::new(v[(i, j)], T::zero());
+            }
+            j += 1;
+        }
+    }
+
+    result
+}
+
+/// Eigendecomposition of a real square matrix with complex eigenvalues and eigenvectors,
+/// reconstructed from LAPACK's packed real conjugate-pair storage.
+#[derive(Clone, Debug)]
+pub struct ComplexEigen<T: Scalar, D: Dim>
+where
+    DefaultAllocator: Allocator<Complex<T>, D> + Allocator<Complex<T>, D, D>,
+{
+    /// The eigenvalues of the decomposed matrix.
+    pub eigenvalues: OVector<Complex<T>, D>,
+    /// The (right) eigenvectors of the decomposed matrix.
+    pub eigenvectors: Option<OMatrix<Complex<T>, D, D>>,
+    /// The left eigenvectors of the decomposed matrix.
+    pub left_eigenvectors: Option<OMatrix<Complex<T>, D, D>>,
 }
 
 /*
@@ -353,10 +651,61 @@ pub trait EigenScalar: Scalar {
         ldvr: i32,
         info: &mut i32,
     ) -> i32;
+    #[allow(missing_docs)]
+    #[allow(clippy::too_many_arguments)]
+    fn xgeevx(
+        balanc: u8,
+        jobvl: u8,
+        jobvr: u8,
+        sense: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        wr: &mut [Self],
+        wi: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        ilo: &mut i32,
+        ihi: &mut i32,
+        scale: &mut [Self],
+        abnrm: &mut Self,
+        rconde: &mut [Self],
+        rcondv: &mut [Self],
+        work: &mut [Self],
+        lwork: i32,
+        iwork: &mut [i32],
+        info: &mut i32,
+    );
+    #[allow(missing_docs)]
+    #[allow(clippy::too_many_arguments)]
+    fn xgeevx_work_size(
+        balanc: u8,
+        jobvl: u8,
+        jobvr: u8,
+        sense: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        wr: &mut [Self],
+        wi: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        ilo: &mut i32,
+        ihi: &mut i32,
+        scale: &mut [Self],
+        abnrm: &mut Self,
+        rconde: &mut [Self],
+        rcondv: &mut [Self],
+        info: &mut i32,
+    ) -> i32;
 }
 
 macro_rules! real_eigensystem_scalar_impl (
-    ($N: ty, $xgeev: path) => (
+    ($N: ty, $xgeev: path, $xgeevx: path) => (
         impl EigenScalar for $N {
             #[inline]
             fn xgeev(jobvl: u8, jobvr: u8, n: i32, a: &mut [Self], lda: i32,
@@ -377,13 +726,137 @@ macro_rules! real_eigensystem_scalar_impl (
                 unsafe { $xgeev(jobvl, jobvr, n, a, lda, wr, wi, vl, ldvl, vr, ldvr, &mut work, lwork, info) };
                 ComplexHelper::real_part(work[0]) as i32
             }
+
+            #[inline]
+            fn xgeevx(balanc: u8, jobvl: u8, jobvr: u8, sense: u8, n: i32, a: &mut [Self], lda: i32,
+                      wr: &mut [Self], wi: &mut [Self],
+                      vl: &mut [Self], ldvl: i32, vr: &mut [Self], ldvr: i32,
+                      ilo: &mut i32, ihi: &mut i32, scale: &mut [Self], abnrm: &mut Self,
+                      rconde: &mut [Self], rcondv: &mut [Self],
+                      work: &mut [Self], lwork: i32, iwork: &mut [i32], info: &mut i32) {
+                unsafe {
+                    $xgeevx(balanc, jobvl, jobvr, sense, n, a, lda, wr, wi, vl, ldvl, vr, ldvr,
+                            ilo, ihi, scale, abnrm, rconde, rcondv, work, lwork, iwork, info)
+                }
+            }
+
+            #[inline]
+            fn xgeevx_work_size(balanc: u8, jobvl: u8, jobvr: u8, sense: u8, n: i32, a: &mut [Self], lda: i32,
+                                wr: &mut [Self], wi: &mut [Self], vl: &mut [Self], ldvl: i32,
+                                vr: &mut [Self], ldvr: i32, ilo: &mut i32, ihi: &mut i32,
+                                scale: &mut [Self], abnrm: &mut Self, rconde: &mut [Self], rcondv: &mut [Self],
+                                info: &mut i32) -> i32 {
+                let mut work = [ Zero::zero() ];
+                let lwork = -1 as i32;
+                let mut iwork = [ 0 ];
+
+                unsafe {
+                    $xgeevx(balanc, jobvl, jobvr, sense, n, a, lda, wr, wi, vl, ldvl, vr, ldvr,
+                            ilo, ihi, scale, abnrm, rconde, rcondv, &mut work, lwork, &mut iwork, info)
+                };
+                ComplexHelper::real_part(work[0]) as i32
+            }
         }
     )
 );
 
-real_eigensystem_scalar_impl!(f32, lapack::sgeev);
-real_eigensystem_scalar_impl!(f64, lapack::dgeev);
+real_eigensystem_scalar_impl!(f32, lapack::sgeev, lapack::sgeevx);
+real_eigensystem_scalar_impl!(f64, lapack::dgeev, lapack::dgeevx);
 
 //// TODO: decomposition of complex matrix and matrices with complex eigenvalues.
 // eigensystem_complex_impl!(f32, lapack::cgeev);
 // eigensystem_complex_impl!(f64, lapack::zgeev);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use na::Matrix2;
+
+    // Checks that `a * eigenvector == eigenvalue * eigenvector`.
+    fn assert_is_eigenpair(a: &Matrix2<f64>, eigenvalue: Complex<f64>, eigenvector: &OVector<Complex<f64>, Const<2>>) {
+        let mut residual = Complex::new(0.0, 0.0);
+        for i in 0..2 {
+            let mut av_i = Complex::new(0.0, 0.0);
+            for j in 0..2 {
+                av_i += eigenvector[j] * a[(i, j)];
+            }
+            residual += (av_i - eigenvalue * eigenvector[i]).norm_sqr();
+        }
+
+        assert!(
+            residual.re.sqrt() < 1.0e-10,
+            "A * v != lambda * v (residual = {})",
+            residual.re.sqrt()
+        );
+    }
+
+    #[test]
+    fn new_complex_reconstructs_conjugate_pair() {
+        // A 90-degree rotation block: purely imaginary conjugate eigenvalues +-i, with
+        // eigenvectors [1, -i] and [1, i] respectively.
+        let a = Matrix2::new(0.0_f64, -1.0, 1.0, 0.0);
+
+        for &(left, right) in &[(true, true), (false, true), (true, false)] {
+            let eigen = Eigen::new_complex(a, left, right)
+                .expect("a 2x2 real matrix always has eigenvalues");
+
+            assert!((eigen.eigenvalues[0] - Complex::new(0.0, 1.0)).norm() < 1.0e-10);
+            assert!((eigen.eigenvalues[1] - Complex::new(0.0, -1.0)).norm() < 1.0e-10);
+
+            if right {
+                let vr = eigen.eigenvectors.unwrap();
+                assert_is_eigenpair(&a, eigen.eigenvalues[0], &vr.column(0).into_owned());
+                assert_is_eigenpair(&a, eigen.eigenvalues[1], &vr.column(1).into_owned());
+            } else {
+                assert!(eigen.eigenvectors.is_none());
+            }
+
+            if left {
+                // LAPACK defines the left eigenvector `u` of `a` by `u^H * a = lambda * u^H`,
+                // i.e. (taking the conjugate transpose of both sides) `a^T * u = conj(lambda) * u`.
+                let at = a.transpose();
+                let vl = eigen.left_eigenvectors.unwrap();
+                assert_is_eigenpair(&at, eigen.eigenvalues[0].conj(), &vl.column(0).into_owned());
+                assert_is_eigenpair(&at, eigen.eigenvalues[1].conj(), &vl.column(1).into_owned());
+            } else {
+                assert!(eigen.left_eigenvectors.is_none());
+            }
+        }
+    }
+
+    #[test]
+    fn new_with_conditioning_matches_eigenpairs_and_reports_plausible_conditioning() {
+        // Upper-triangular, distinct real eigenvalues 2 and 3: well-conditioned, non-defective.
+        let a = Matrix2::new(2.0_f64, 1.0, 0.0, 3.0);
+
+        let eigen = Eigen::new_with_conditioning(a, true, true)
+            .expect("a well-conditioned 2x2 matrix always succeeds");
+
+        for i in 0..2 {
+            assert!(eigen.eigenvalues[i].im.abs() < 1.0e-10);
+            assert!(eigen.rconde[i] > 0.0 && eigen.rconde[i] <= 1.0 + 1.0e-10);
+            assert!(eigen.rcondv[i] > 0.0 && eigen.rcondv[i] <= 1.0 + 1.0e-10);
+        }
+
+        let vr = eigen.eigenvectors.unwrap();
+        assert_is_eigenpair(&a, eigen.eigenvalues[0], &vr.column(0).into_owned());
+        assert_is_eigenpair(&a, eigen.eigenvalues[1], &vr.column(1).into_owned());
+
+        // See `new_complex_reconstructs_conjugate_pair` for why `a^T` and `conj(lambda)` are used.
+        let at = a.transpose();
+        let vl = eigen.left_eigenvectors.unwrap();
+        assert_is_eigenpair(&at, eigen.eigenvalues[0].conj(), &vl.column(0).into_owned());
+        assert_is_eigenpair(&at, eigen.eigenvalues[1].conj(), &vl.column(1).into_owned());
+    }
+
+    #[test]
+    fn new_with_conditioning_respects_vector_flags() {
+        let a = Matrix2::new(2.0_f64, 1.0, 0.0, 3.0);
+
+        let eigen = Eigen::new_with_conditioning(a, false, false)
+            .expect("a well-conditioned 2x2 matrix always succeeds");
+
+        assert!(eigen.eigenvectors.is_none());
+        assert!(eigen.left_eigenvectors.is_none());
+    }
+}
@@ -0,0 +1,334 @@
+#[cfg(feature = "serde-serialize")]
+use serde::{Deserialize, Serialize};
+
+use num::Zero;
+use num_complex::Complex;
+
+use simba::scalar::RealField;
+
+use crate::ComplexHelper;
+use na::allocator::Allocator;
+use na::dimension::{Const, Dim};
+use na::{DefaultAllocator, Matrix, OMatrix, OVector, Scalar};
+
+use lapack;
+
+/// Generalized eigendecomposition of a real square matrix pair `(A, B)` solving `A x = λ B x`.
+#[cfg_attr(feature = "serde-serialize", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(
+        bound(serialize = "DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+         OVector<T, D>: Serialize,
+         OMatrix<T, D, D>: Serialize")
+    )
+)]
+#[cfg_attr(
+    feature = "serde-serialize",
+    serde(
+        bound(deserialize = "DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+         OVector<T, D>: Serialize,
+         OMatrix<T, D, D>: Deserialize<'de>")
+    )
+)]
+#[derive(Clone, Debug)]
+pub struct GeneralizedEigen<T: Scalar, D: Dim>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, D, D>,
+{
+    alphar: OVector<T, D>,
+    alphai: OVector<T, D>,
+    beta: OVector<T, D>,
+    /// The (right) eigenvectors of the decomposed matrix pair.
+    pub eigenvectors: Option<OMatrix<T, D, D>>,
+    /// The left eigenvectors of the decomposed matrix pair.
+    pub left_eigenvectors: Option<OMatrix<T, D, D>>,
+}
+
+impl<T: GeneralizedEigenScalar + RealField, D: Dim> GeneralizedEigen<T, D>
+where
+    DefaultAllocator: Allocator<T, D, D> + Allocator<T, D>,
+{
+    /// Computes the generalized eigenvalues and eigenvectors of the matrix pair `(a, b)` such
+    /// that `a * v == lambda * b * v`.
+    ///
+    /// If `eigenvectors` is `false` then, the eigenvectors are not computed explicitly.
+    pub fn new(
+        mut a: OMatrix<T, D, D>,
+        mut b: OMatrix<T, D, D>,
+        left_eigenvectors: bool,
+        eigenvectors: bool,
+    ) -> Option<GeneralizedEigen<T, D>> {
+        assert!(
+            a.is_square() && b.is_square(),
+            "Unable to compute the generalized eigenvalue decomposition of non-square matrices."
+        );
+        assert!(
+            a.shape() == b.shape(),
+            "Unable to compute the generalized eigenvalue decomposition of matrices with different shapes."
+        );
+
+        let ljob = if left_eigenvectors { b'V' } else { b'T' };
+        let rjob = if eigenvectors { b'V' } else { b'T' };
+
+        let (nrows, ncols) = a.shape_generic();
+        let n = nrows.value();
+
+        let lda = n as i32;
+        let ldb = n as i32;
+
+        // TODO: avoid the initializations?
+        let mut alphar = Matrix::zeros_generic(nrows, Const::<1>);
+        let mut alphai = Matrix::zeros_generic(nrows, Const::<1>);
+        let mut beta = Matrix::zeros_generic(nrows, Const::<1>);
+
+        let mut info = 0;
+        let mut placeholder1 = [T::zero()];
+        let mut placeholder2 = [T::zero()];
+
+        let lwork = T::xggev_work_size(
+            ljob,
+            rjob,
+            n as i32,
+            a.as_mut_slice(),
+            lda,
+            b.as_mut_slice(),
+            ldb,
+            alphar.as_mut_slice(),
+            alphai.as_mut_slice(),
+            beta.as_mut_slice(),
+            &mut placeholder1,
+            n as i32,
+            &mut placeholder2,
+            n as i32,
+            &mut info,
+        );
+
+        lapack_check!(info);
+
+        let mut work = vec![T::zero(); lwork as usize];
+
+        let mut vl = if left_eigenvectors {
+            Some(Matrix::zeros_generic(nrows, ncols))
+        } else {
+            None
+        };
+        let mut vr = if eigenvectors {
+            Some(Matrix::zeros_generic(nrows, ncols))
+        } else {
+            None
+        };
+
+        T::xggev(
+            ljob,
+            rjob,
+            n as i32,
+            a.as_mut_slice(),
+            lda,
+            b.as_mut_slice(),
+            ldb,
+            alphar.as_mut_slice(),
+            alphai.as_mut_slice(),
+            beta.as_mut_slice(),
+            vl.as_mut().map_or(&mut placeholder1[..], |vl| vl.as_mut_slice()),
+            if left_eigenvectors { n as i32 } else { 1 },
+            vr.as_mut().map_or(&mut placeholder2[..], |vr| vr.as_mut_slice()),
+            if eigenvectors { n as i32 } else { 1 },
+            &mut work,
+            lwork,
+            &mut info,
+        );
+        lapack_check!(info);
+
+        Some(Self {
+            alphar,
+            alphai,
+            beta,
+            left_eigenvectors: vl,
+            eigenvectors: vr,
+        })
+    }
+
+    /// The numerators `alphar + i·alphai` of the raw `(alpha, beta)` representation of the
+    /// generalized eigenvalues, as returned by `dggev`/`sggev`.
+    #[must_use]
+    pub fn raw_alphas(&self) -> (&OVector<T, D>, &OVector<T, D>) {
+        (&self.alphar, &self.alphai)
+    }
+
+    /// The denominators of the raw `(alpha, beta)` representation of the generalized
+    /// eigenvalues, as returned by `dggev`/`sggev`.
+    ///
+    /// A zero entry means the corresponding eigenvalue is infinite; see [`Self::raw_alphas`].
+    #[must_use]
+    pub fn raw_betas(&self) -> &OVector<T, D> {
+        &self.beta
+    }
+
+    /// The complex generalized eigenvalues `(alphar + i·alphai) / beta`.
+    ///
+    /// An eigenvalue whose `beta` is zero is infinite and is reported as
+    /// `Complex::new(T::max_value(), T::zero())` rather than dividing by zero (a complex
+    /// division by a zero denominator would produce `NaN` components); use
+    /// [`Self::raw_alphas`] and [`Self::raw_betas`] to detect this case explicitly.
+    #[must_use]
+    pub fn complex_eigenvalues(&self) -> OVector<Complex<T>, D>
+    where
+        DefaultAllocator: Allocator<Complex<T>, D>,
+    {
+        let mut res = Matrix::zeros_generic(self.alphar.shape_generic().0, Const::<1>);
+
+        for i in 0..res.len() {
+            res[i] = if self.beta[i].is_zero() {
+                Complex::new(
+                    T::max_value().expect("RealField::max_value() is always defined for f32/f64"),
+                    T::zero(),
+                )
+            } else {
+                let num = Complex::new(self.alphar[i], self.alphai[i]);
+                num / Complex::new(self.beta[i], T::zero())
+            };
+        }
+
+        res
+    }
+}
+
+/*
+ *
+ * Lapack functions dispatch.
+ *
+ */
+/// Trait implemented by scalar type for which Lapack function exist to compute the
+/// generalized eigendecomposition.
+pub trait GeneralizedEigenScalar: Scalar {
+    #[allow(missing_docs)]
+    #[allow(clippy::too_many_arguments)]
+    fn xggev(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        alphar: &mut [Self],
+        alphai: &mut [Self],
+        beta: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        work: &mut [Self],
+        lwork: i32,
+        info: &mut i32,
+    );
+    #[allow(missing_docs)]
+    #[allow(clippy::too_many_arguments)]
+    fn xggev_work_size(
+        jobvl: u8,
+        jobvr: u8,
+        n: i32,
+        a: &mut [Self],
+        lda: i32,
+        b: &mut [Self],
+        ldb: i32,
+        alphar: &mut [Self],
+        alphai: &mut [Self],
+        beta: &mut [Self],
+        vl: &mut [Self],
+        ldvl: i32,
+        vr: &mut [Self],
+        ldvr: i32,
+        info: &mut i32,
+    ) -> i32;
+}
+
+macro_rules! generalized_eigensystem_scalar_impl (
+    ($N: ty, $xggev: path) => (
+        impl GeneralizedEigenScalar for $N {
+            #[inline]
+            fn xggev(jobvl: u8, jobvr: u8, n: i32, a: &mut [Self], lda: i32,
+                     b: &mut [Self], ldb: i32,
+                     alphar: &mut [Self], alphai: &mut [Self], beta: &mut [Self],
+                     vl: &mut [Self], ldvl: i32, vr: &mut [Self], ldvr: i32,
+                     work: &mut [Self], lwork: i32, info: &mut i32) {
+                unsafe { $xggev(jobvl, jobvr, n, a, lda, b, ldb, alphar, alphai, beta, vl, ldvl, vr, ldvr, work, lwork, info) }
+            }
+
+            #[inline]
+            fn xggev_work_size(jobvl: u8, jobvr: u8, n: i32, a: &mut [Self], lda: i32,
+                               b: &mut [Self], ldb: i32,
+                               alphar: &mut [Self], alphai: &mut [Self], beta: &mut [Self],
+                               vl: &mut [Self], ldvl: i32, vr: &mut [Self], ldvr: i32, info: &mut i32) -> i32 {
+                let mut work = [ Zero::zero() ];
+                let lwork = -1 as i32;
+
+                unsafe { $xggev(jobvl, jobvr, n, a, lda, b, ldb, alphar, alphai, beta, vl, ldvl, vr, ldvr, &mut work, lwork, info) };
+                ComplexHelper::real_part(work[0]) as i32
+            }
+        }
+    )
+);
+
+generalized_eigensystem_scalar_impl!(f32, lapack::sggev);
+generalized_eigensystem_scalar_impl!(f64, lapack::dggev);
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use na::Matrix2;
+
+    #[test]
+    fn generalized_eigenvalues_match_standard_eigenproblem_when_b_is_identity() {
+        let a = Matrix2::new(2.0_f64, 0.0, 0.0, 3.0);
+        let b = Matrix2::identity();
+
+        let eigen = GeneralizedEigen::new(a, b, true, true)
+            .expect("a well-posed generalized eigenproblem always succeeds");
+        let eigenvalues = eigen.complex_eigenvalues();
+        let vr = eigen.eigenvectors.unwrap();
+
+        for i in 0..2 {
+            let lambda = eigenvalues[i];
+            assert!(lambda.im.abs() < 1.0e-10);
+
+            let v = vr.column(i).into_owned();
+            let av = a * v;
+            let bv = b * v;
+            let residual = (av - bv * lambda.re).norm();
+            assert!(residual < 1.0e-10, "A * v != lambda * B * v (residual = {})", residual);
+        }
+    }
+
+    #[test]
+    fn singular_b_reports_an_infinite_eigenvalue() {
+        // `B` is singular, so one of the two generalized eigenvalues is infinite (`beta == 0`).
+        let a = Matrix2::identity();
+        let b = Matrix2::new(1.0_f64, 0.0, 0.0, 0.0);
+
+        let eigen = GeneralizedEigen::new(a, b, false, true)
+            .expect("a well-posed generalized eigenproblem always succeeds");
+
+        let betas = eigen.raw_betas();
+        let infinite_index = betas.iter().position(|beta| beta.is_zero()).expect(
+            "a singular B must produce at least one infinite (beta == 0) generalized eigenvalue",
+        );
+        let finite_index = 1 - infinite_index;
+
+        let eigenvalues = eigen.complex_eigenvalues();
+        assert_eq!(eigenvalues[infinite_index], Complex::new(<f64 as RealField>::max_value().unwrap(), 0.0));
+
+        let vr = eigen.eigenvectors.unwrap();
+
+        // The eigenvector for the infinite eigenvalue lies in the null space of `B`.
+        let v_inf = vr.column(infinite_index).into_owned();
+        assert!((b * v_inf).norm() < 1.0e-10);
+
+        // The finite eigenvalue still satisfies `A * v == lambda * B * v`.
+        let lambda = eigenvalues[finite_index].re;
+        let v_fin = vr.column(finite_index).into_owned();
+        let residual = (a * v_fin - b * v_fin * lambda).norm();
+        assert!(residual < 1.0e-10, "A * v != lambda * B * v (residual = {})", residual);
+    }
+}
@@ -1,22 +1,139 @@
-use crate::{SVector, Scalar};
+use num::{One, Zero};
 
-/// A scaling represents a non-uniform scale transformation
-pub struct Scaling<T: Scalar, const D: usize>(pub SVector<T, D>);
+use crate::base::allocator::Allocator;
+use crate::base::dimension::{Const, Dim, DimName, DimNameAdd, DimNameSum, U1};
+use crate::base::{DefaultAllocator, OMatrix, OPoint, OVector, SVector, Scalar};
+use crate::ClosedMul;
 
-impl<T, const D: usize> From<SVector<T, D>> for Scaling<T, D>
-    where T: Scalar
+/// A scaling represents a non-uniform scale transformation.
+///
+/// This is the `Dim`-generic counterpart of [`Scaling`], following the same `O`-prefixed
+/// convention as [`OPoint`]/[`Point`] and [`OVector`]/[`Vector`].
+pub struct OScaling<T: Scalar, D: Dim>(pub OVector<T, D>)
+where
+    DefaultAllocator: Allocator<T, D>;
+
+/// A scaling represents a non-uniform scale transformation.
+pub type Scaling<T, const D: usize> = OScaling<T, Const<D>>;
+
+impl<T: Scalar + Clone, D: Dim> Clone for OScaling<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
+    OVector<T, D>: Clone,
+{
+    fn clone(&self) -> Self {
+        OScaling(self.0.clone())
+    }
+}
+
+impl<T: Scalar, D: DimName> OScaling<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
 {
-    fn from(other: SVector<T, D>) -> Self
+    /// Creates a scaling that does not scale anything, i.e., whose components are all `1`.
+    ///
+    /// # Example
+    /// ```
+    /// # use nalgebra::{Scaling3, Vector3};
+    /// let s = Scaling3::<f64>::identity();
+    /// assert_eq!(s.0, Vector3::new(1.0, 1.0, 1.0));
+    /// ```
+    #[inline]
+    pub fn identity() -> Self
+    where
+        T: One,
     {
-        return Scaling::<T, D>(other);
+        OScaling(OVector::from_element(T::one()))
     }
 }
 
-impl<T, const D: usize> Into<SVector<T, D>> for Scaling<T, D>
-    where T: Scalar
+impl<T: Scalar, D: Dim> OScaling<T, D>
+where
+    DefaultAllocator: Allocator<T, D>,
 {
-    fn into(self) -> SVector<T, D>
+    /// The inverse of this scaling, obtained by taking the component-wise reciprocal, or `None`
+    /// if any component is zero.
+    #[inline]
+    #[must_use]
+    pub fn try_inverse(&self) -> Option<Self>
+    where
+        T: Zero + PartialEq + core::ops::Div<Output = T> + One,
+    {
+        if self.0.iter().any(|e| *e == T::zero()) {
+            None
+        } else {
+            Some(OScaling(self.0.map(|e| T::one() / e)))
+        }
+    }
+
+    /// The inverse of this scaling, obtained by taking the component-wise reciprocal.
+    ///
+    /// # Panics
+    /// Panics if any component of this scaling is zero.
+    #[inline]
+    #[must_use]
+    pub fn inverse(&self) -> Self
+    where
+        T: Zero + PartialEq + core::ops::Div<Output = T> + One,
+    {
+        self.try_inverse()
+            .expect("Unable to invert a scaling with a zero component.")
+    }
+
+    /// Converts this scaling into its equivalent homogeneous transformation matrix, with the
+    /// scale factors on the diagonal and a trailing `1`.
+    #[inline]
+    #[must_use]
+    pub fn to_homogeneous(&self) -> OMatrix<T, DimNameSum<D, U1>, DimNameSum<D, U1>>
+    where
+        T: Zero + One,
+        D: DimNameAdd<U1>,
+        DefaultAllocator: Allocator<T, DimNameSum<D, U1>, DimNameSum<D, U1>>,
+    {
+        let mut res = OMatrix::<T, DimNameSum<D, U1>, DimNameSum<D, U1>>::identity();
+
+        for i in 0..self.0.len() {
+            res[(i, i)] = self.0[i].clone();
+        }
+
+        res
+    }
+
+    /// Applies this scaling to the given point, component-wise.
+    #[inline]
+    #[must_use]
+    pub fn transform_point(&self, pt: &OPoint<T, D>) -> OPoint<T, D>
+    where
+        T: ClosedMul,
+    {
+        OPoint::from(self.0.component_mul(&pt.coords))
+    }
+
+    /// Applies this scaling to the given vector, component-wise.
+    #[inline]
+    #[must_use]
+    pub fn transform_vector(&self, v: &OVector<T, D>) -> OVector<T, D>
+    where
+        T: ClosedMul,
     {
-        return self.0;
+        self.0.component_mul(v)
+    }
+}
+
+impl<T, const D: usize> From<SVector<T, D>> for Scaling<T, D>
+where
+    T: Scalar,
+{
+    fn from(other: SVector<T, D>) -> Self {
+        Scaling::<T, D>(other)
+    }
+}
+
+impl<T, const D: usize> Into<SVector<T, D>> for Scaling<T, D>
+where
+    T: Scalar,
+{
+    fn into(self) -> SVector<T, D> {
+        self.0
     }
 }
@@ -0,0 +1,14 @@
+use crate::geometry::Scaling;
+
+/// A 1-dimensional scaling.
+pub type Scaling1<T> = Scaling<T, 1>;
+/// A 2-dimensional scaling.
+pub type Scaling2<T> = Scaling<T, 2>;
+/// A 3-dimensional scaling.
+pub type Scaling3<T> = Scaling<T, 3>;
+/// A 4-dimensional scaling.
+pub type Scaling4<T> = Scaling<T, 4>;
+/// A 5-dimensional scaling.
+pub type Scaling5<T> = Scaling<T, 5>;
+/// A 6-dimensional scaling.
+pub type Scaling6<T> = Scaling<T, 6>;
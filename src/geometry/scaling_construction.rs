@@ -0,0 +1,27 @@
+use crate::base::dimension::Const;
+use crate::base::{Scalar, SVector};
+use crate::geometry::OScaling;
+
+macro_rules! componentwise_constructors_impl(
+    ($($D: ty, $($args: ident),*);* $(;)*) => {$(
+        impl<T> OScaling<T, $D>
+        where
+            T: Scalar,
+        {
+            /// Initializes this scaling from its components.
+            #[inline]
+            pub fn new($($args: T),*) -> Self {
+                Self(SVector::<T, $D>::new($($args),*))
+            }
+        }
+    )*}
+);
+
+componentwise_constructors_impl!(
+    Const<1>, x;
+    Const<2>, x, y;
+    Const<3>, x, y, z;
+    Const<4>, x, y, z, w;
+    Const<5>, x, y, z, w, a;
+    Const<6>, x, y, z, w, a, b;
+);
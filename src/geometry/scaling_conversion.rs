@@ -0,0 +1,14 @@
+use crate::geometry::{OScaling, TAffine, Transform};
+use crate::{
+    allocator::Allocator, DefaultAllocator, DimNameAdd, DimNameSum, RealField, U1,
+};
+
+impl<T: RealField, D: DimNameAdd<U1>> From<OScaling<T, D>> for Transform<T, TAffine, D>
+where
+    DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameSum<D, U1>, DimNameSum<D, U1>>,
+{
+    #[inline]
+    fn from(s: OScaling<T, D>) -> Self {
+        Transform::from_matrix_unchecked(s.to_homogeneous())
+    }
+}
@@ -5,8 +5,13 @@ use std::ops::{
     DivAssign
 };
 
+use crate::geometry::{Isometry, TAffine, Transform, Translation};
 use crate::OScaling;
-use crate::{ClosedDiv, ClosedMul, DefaultAllocator, DimName, OVector, allocator::Allocator, Scalar, OPoint};
+use crate::{
+    allocator::Allocator, ClosedDiv, ClosedMul, DefaultAllocator, DimName, DimNameAdd,
+    DimNameSum, OPoint, OVector, RealField, Scalar, U1,
+};
+use crate::base::AbstractRotation;
 
 impl<T, D: DimName> Mul<OVector<T, D>> for OScaling<T, D>
     where T: Scalar + ClosedMul, DefaultAllocator: Allocator<T, D>
@@ -109,3 +114,83 @@ impl<T, D: DimName> Div<OPoint<T, D>> for OScaling<T, D>
         return OPoint::from(self.0.component_div(&rhs.coords));
     }
 }
+
+impl<T: RealField, D: DimNameAdd<U1>> Mul<Translation<T, D>> for OScaling<T, D>
+    where DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameSum<D, U1>, DimNameSum<D, U1>>
+{
+    type Output = Transform<T, TAffine, D>;
+
+    fn mul(self, rhs: Translation<T, D>) -> Self::Output
+    {
+        Transform::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous())
+    }
+}
+
+impl<T: RealField, D: DimNameAdd<U1>> Mul<OScaling<T, D>> for Translation<T, D>
+    where DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameSum<D, U1>, DimNameSum<D, U1>>
+{
+    type Output = Transform<T, TAffine, D>;
+
+    fn mul(self, rhs: OScaling<T, D>) -> Self::Output
+    {
+        Transform::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous())
+    }
+}
+
+impl<T: RealField, R, D: DimNameAdd<U1>> Mul<Isometry<T, R, D>> for OScaling<T, D>
+    where
+        R: AbstractRotation<T, D>,
+        DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameSum<D, U1>, DimNameSum<D, U1>>
+{
+    type Output = Transform<T, TAffine, D>;
+
+    fn mul(self, rhs: Isometry<T, R, D>) -> Self::Output
+    {
+        Transform::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous())
+    }
+}
+
+impl<T: RealField, R, D: DimNameAdd<U1>> Mul<OScaling<T, D>> for Isometry<T, R, D>
+    where
+        R: AbstractRotation<T, D>,
+        DefaultAllocator: Allocator<T, D> + Allocator<T, DimNameSum<D, U1>, DimNameSum<D, U1>>
+{
+    type Output = Transform<T, TAffine, D>;
+
+    fn mul(self, rhs: OScaling<T, D>) -> Self::Output
+    {
+        Transform::from_matrix_unchecked(self.to_homogeneous() * rhs.to_homogeneous())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point2, Scaling2, Translation2};
+
+    #[test]
+    fn scaling_times_translation_matches_applying_translation_then_scaling() {
+        let s = Scaling2::new(2.0, 3.0);
+        let t = Translation2::new(1.0, -1.0);
+        let p = Point2::new(5.0, 7.0);
+
+        let composed = s.clone() * t.clone();
+        assert_eq!(*composed.matrix(), s.to_homogeneous() * t.to_homogeneous());
+
+        // `s * t` must scale the already-translated point, not translate the already-scaled one.
+        let expected = s.transform_point(&t.transform_point(&p));
+        assert_eq!(composed.transform_point(&p), expected);
+    }
+
+    #[test]
+    fn translation_times_scaling_scales_before_translating() {
+        let s = Scaling2::new(2.0, 3.0);
+        let t = Translation2::new(1.0, -1.0);
+        let p = Point2::new(5.0, 7.0);
+
+        let composed = t.clone() * s.clone();
+        assert_eq!(*composed.matrix(), t.to_homogeneous() * s.to_homogeneous());
+
+        let expected = t.transform_point(&s.transform_point(&p));
+        assert_eq!(composed.transform_point(&p), expected);
+    }
+}